@@ -5,7 +5,9 @@ use crate::{
 
 use engine::runtime::memories::buffer::Buffer;
 use iota_stronghold::{
-    procedures::{FatalProcedureError, GenerateSecret, ProcedureOutput, Products, UseSecret},
+    procedures::{
+        DeriveSecret, FatalProcedureError, GenerateSecret, ProcedureOutput, Products, UseSecret,
+    },
     Location,
 };
 
@@ -21,6 +23,10 @@ pub enum Es256kProcs {
     PublicKey(PublicKey),
     Sign(Sign),
     Verify(Verify),
+    Recover(Recover),
+    DiffieHellman(DiffieHellman),
+    TweakAdd(TweakAdd),
+    PublicKeyTweakAdd(PublicKeyTweakAdd),
 }
 
 /// Returns a Es256 public key from an already existing private key in the vault.
@@ -36,10 +42,22 @@ pub struct GenerateKey {
 }
 
 /// Signs a message using the indicated private key from the vault.
+///
+/// When [`Sign::recoverable`] is set, the output is a 65-byte `[r || s || v]`
+/// blob instead of a plain signature, where `v` is the 2-bit ECDSA recovery
+/// id. That blob lets a verifier reconstruct the signer's public key from
+/// the signature alone via [`Recover`], without ever learning the private
+/// key.
+///
+/// When [`Sign::prehashed`] is set, `msg` is treated as an already-hashed,
+/// exactly 32-byte digest (e.g. a keccak256 Ethereum transaction hash) and
+/// signed directly instead of being hashed again internally.
 #[derive(Clone, GuardDebug, Serialize, Deserialize)]
 pub struct Sign {
     pub msg: Vec<u8>,
     pub private_key: Location,
+    pub recoverable: bool,
+    pub prehashed: bool,
 }
 
 /// Verifies that a message was signed by the indicated private key in the vault.
@@ -50,10 +68,71 @@ pub struct Verify {
     pub msg: Vec<u8>,
     pub signature: Vec<u8>,
     pub private_key: Location,
+    pub prehashed: bool,
 }
 
-generic_procedures!(Es256kProcs, UseSecret<1> => {PublicKey, Sign, Verify});
+/// Recovers the signer's Es256k public key from a message and a recoverable
+/// signature produced by [`Sign`] with `recoverable` set.
+///
+/// Unlike the other procedures in this module, `Recover` never touches the
+/// vault: it is pure math over its own inputs, so it does not implement
+/// [`UseSecret`] and has no `source()`. It plugs directly into the generic
+/// procedure machinery, with [`ProcedureExt::input`]/[`ProcedureExt::output`]
+/// both reporting `None`.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct Recover {
+    pub msg: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub prehashed: bool,
+}
+
+/// Selects what [`DiffieHellman`] returns for the computed shared point.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum DiffieHellmanOutput {
+    /// The raw SEC1-compressed shared point (33 bytes).
+    RawPoint,
+    /// SHA-256 of the shared point's x-coordinate alone (32 bytes). Unlike
+    /// libsecp256k1's `secp256k1_ecdh_hash_function_default`, no y-parity
+    /// prefix byte is hashed, so the two outputs differ.
+    HashedX,
+}
+
+/// Computes an ECDH shared secret between a vault-held Es256k private key and
+/// a peer's SEC1-encoded public key. The private key never leaves the vault.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct DiffieHellman {
+    pub private_key: Location,
+    pub peer_public_key: Vec<u8>,
+    pub output: DiffieHellmanOutput,
+}
+
+/// Derives a child private key from a vault-held Es256k parent key by
+/// additive tweaking, as in BIP32 non-hardened derivation: `child = (parent +
+/// tweak) mod n`. The parent key is read from the vault and the child key is
+/// written back to [`TweakAdd::output`] without ever leaving the vault.
+///
+/// Unlike [`GenerateKey`], this both reads a guarded secret and writes a new
+/// one, so it runs through [`DeriveSecret`] rather than plain
+/// [`UseSecret`]/[`GenerateSecret`].
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct TweakAdd {
+    pub parent_key: Location,
+    pub tweak: Vec<u8>,
+    pub output: Location,
+}
+
+/// Applies the same additive tweak as [`TweakAdd`] to a public key instead of
+/// a vault secret: `child = P + tweak·G`. This lets callers derive child
+/// public keys without touching the vault at all.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct PublicKeyTweakAdd {
+    pub parent_public_key: Vec<u8>,
+    pub tweak: Vec<u8>,
+}
+
+generic_procedures!(Es256kProcs, UseSecret<1> => {PublicKey, Sign, Verify, DiffieHellman});
 ext_procs!(Es256kProcs, GenerateSecret => {GenerateKey});
+ext_procs!(Es256kProcs, DeriveSecret<1> => {TweakAdd});
 
 impl UseSecret<1> for PublicKey {
     type Output = Vec<u8>;
@@ -81,6 +160,63 @@ impl UseSecret<1> for Sign {
     type Output = Vec<u8>;
 
     fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        if self.prehashed && self.msg.len() != 32 {
+            return Err(String::from(
+                "Es256k: Prehashed message must be exactly 32 bytes",
+            )
+            .into());
+        }
+
+        if self.recoverable {
+            let sk = k256::ecdsa::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
+                String::from(format!(
+                    "Es256k: Failed to get signing key from guard {:?}",
+                    e
+                ))
+            })?;
+
+            // `sign_recoverable`/`sign_prehash_recoverable` normalize `s` to the
+            // lower half of the curve order and flip the recovery id's parity
+            // bit to match, so the blob below is always low-s.
+            let (sig, recid) = if self.prehashed {
+                sk.sign_prehash_recoverable(&self.msg).map_err(|e| {
+                    String::from(format!(
+                        "Es256k: Failed to create recoverable signature {:?}",
+                        e
+                    ))
+                })?
+            } else {
+                sk.sign_recoverable(&self.msg).map_err(|e| {
+                    String::from(format!(
+                        "Es256k: Failed to create recoverable signature {:?}",
+                        e
+                    ))
+                })?
+            };
+
+            let mut bytes = sig.to_bytes().to_vec();
+            bytes.push(recid.to_byte());
+
+            return Ok(bytes);
+        }
+
+        if self.prehashed {
+            use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+            let sk = k256::ecdsa::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
+                String::from(format!(
+                    "Es256k: Failed to get signing key from guard {:?}",
+                    e
+                ))
+            })?;
+
+            let sig: k256::ecdsa::Signature = sk.sign_prehash(&self.msg).map_err(|e| {
+                String::from(format!("Es256k: Failed to sign prehashed digest {:?}", e))
+            })?;
+
+            return Ok(sig.to_bytes().to_vec());
+        }
+
         let sk =
             <Es256k as Algorithm>::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
                 String::from(format!(
@@ -103,6 +239,40 @@ impl UseSecret<1> for Verify {
     type Output = Vec<u8>;
 
     fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        if self.prehashed && self.msg.len() != 32 {
+            return Err(String::from(
+                "Es256k: Prehashed message must be exactly 32 bytes",
+            )
+            .into());
+        }
+
+        if self.prehashed {
+            use k256::ecdsa::signature::hazmat::PrehashVerifier;
+
+            let sk = k256::ecdsa::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
+                String::from(format!(
+                    "Es256k: Failed to get signing key from guard {:?}",
+                    e
+                ))
+            })?;
+
+            let sig = k256::ecdsa::Signature::from_slice(&self.signature).map_err(|e| {
+                String::from(format!(
+                    "Es256k: Failed to get signature from vector {:?}",
+                    e
+                ))
+            })?;
+
+            let vk = sk.verifying_key();
+            let res = vk.verify_prehash(&self.msg, &sig).is_ok();
+
+            return if res {
+                Ok(u8::to_be_bytes(1).to_vec())
+            } else {
+                Ok(u8::to_be_bytes(0).to_vec())
+            };
+        }
+
         let sk =
             <Es256k as Algorithm>::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
                 String::from(format!(
@@ -134,6 +304,111 @@ impl UseSecret<1> for Verify {
     }
 }
 
+impl UseSecret<1> for DiffieHellman {
+    type Output = Vec<u8>;
+
+    fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        use k256::elliptic_curve::{
+            group::Group,
+            sec1::{FromEncodedPoint, ToEncodedPoint},
+        };
+        use sha2::Digest;
+
+        let sk = k256::ecdsa::SigningKey::from_slice(&guard[0].borrow()).map_err(|e| {
+            String::from(format!(
+                "Es256k: Failed to get signing key from guard {:?}",
+                e
+            ))
+        })?;
+
+        let peer_point = k256::EncodedPoint::from_bytes(&self.peer_public_key).map_err(|e| {
+            String::from(format!("Es256k: Failed to parse peer public key {:?}", e))
+        })?;
+
+        let peer_affine: k256::AffinePoint =
+            Option::from(k256::AffinePoint::from_encoded_point(&peer_point)).ok_or_else(|| {
+                String::from("Es256k: Peer public key does not decode to a curve point")
+            })?;
+
+        let shared_point =
+            k256::ProjectivePoint::from(peer_affine) * sk.as_nonzero_scalar().as_ref();
+
+        if bool::from(shared_point.is_identity()) {
+            return Err(String::from(
+                "Es256k: Peer public key decodes to the identity point",
+            )
+            .into());
+        }
+
+        let encoded = shared_point.to_affine().to_encoded_point(true);
+
+        match self.output {
+            DiffieHellmanOutput::RawPoint => Ok(encoded.as_bytes().to_vec()),
+            DiffieHellmanOutput::HashedX => {
+                let x = encoded
+                    .x()
+                    .ok_or_else(|| String::from("Es256k: Shared point has no x-coordinate"))?;
+                Ok(sha2::Sha256::digest(x).to_vec())
+            }
+        }
+    }
+
+    fn source(&self) -> [Location; 1] {
+        [self.private_key.clone()]
+    }
+}
+
+impl Procedure for Recover {
+    type Output = Vec<u8>;
+
+    fn execute<R: Runner>(self, _runner: &R) -> Result<Self::Output, ProcedureError> {
+        if self.signature.len() != 65 {
+            return Err(FatalProcedureError::from(String::from(
+                "Es256k: Recover expects a 65-byte [r || s || v] signature",
+            ))
+            .into());
+        }
+
+        if self.prehashed && self.msg.len() != 32 {
+            return Err(FatalProcedureError::from(String::from(
+                "Es256k: Prehashed message must be exactly 32 bytes",
+            ))
+            .into());
+        }
+
+        let (rs, v) = self.signature.split_at(64);
+
+        let recid = k256::ecdsa::RecoveryId::from_byte(v[0]).ok_or_else(|| {
+            FatalProcedureError::from(String::from("Es256k: Invalid recovery id"))
+        })?;
+
+        let sig = k256::ecdsa::Signature::from_slice(rs).map_err(|e| {
+            FatalProcedureError::from(String::from(format!(
+                "Es256k: Failed to parse recoverable signature {:?}",
+                e
+            )))
+        })?;
+
+        // A signature produced over a pre-hashed digest (`Sign` with
+        // `prehashed: true`) must be recovered via the matching digest-domain
+        // API: `recover_from_msg` would hash `msg` again internally and
+        // derive the wrong public key.
+        let vk = if self.prehashed {
+            k256::ecdsa::VerifyingKey::recover_from_prehash(&self.msg, &sig, recid)
+        } else {
+            k256::ecdsa::VerifyingKey::recover_from_msg(&self.msg, &sig, recid)
+        }
+        .map_err(|e| {
+            FatalProcedureError::from(String::from(format!(
+                "Es256k: Failed to recover public key {:?}",
+                e
+            )))
+        })?;
+
+        Ok(vk.to_encoded_point(true).as_bytes().to_vec())
+    }
+}
+
 impl GenerateSecret for GenerateKey {
     type Output = ();
 
@@ -152,6 +427,103 @@ impl GenerateSecret for GenerateKey {
     }
 }
 
+impl DeriveSecret<1> for TweakAdd {
+    type Output = ();
+
+    fn derive(
+        self,
+        guard: [Buffer<u8>; 1],
+    ) -> Result<Products<Self::Output>, FatalProcedureError> {
+        let parent_bytes: k256::FieldBytes =
+            k256::FieldBytes::from_exact_iter(guard[0].borrow().iter().copied()).ok_or_else(
+                || String::from("Es256k: Failed to get signing key from guard"),
+            )?;
+
+        let parent = k256::Scalar::from_repr(parent_bytes)
+            .into_option()
+            .ok_or_else(|| String::from("Es256k: Parent key is not a valid scalar"))?;
+
+        let tweak_bytes: k256::FieldBytes =
+            k256::FieldBytes::from_exact_iter(self.tweak.iter().copied())
+                .ok_or_else(|| String::from("Es256k: Tweak must be exactly 32 bytes"))?;
+
+        let tweak = k256::Scalar::from_repr(tweak_bytes)
+            .into_option()
+            .ok_or_else(|| String::from("Es256k: Tweak is not less than the curve order"))?;
+
+        let child = parent + tweak;
+
+        if bool::from(child.is_zero()) {
+            return Err(String::from("Es256k: Tweaked key is zero").into());
+        }
+
+        Ok(Products {
+            secret: Zeroizing::new(child.to_bytes().to_vec()),
+            output: (),
+        })
+    }
+
+    fn source(&self) -> [Location; 1] {
+        [self.parent_key.clone()]
+    }
+
+    fn target(&self) -> &Location {
+        &self.output
+    }
+}
+
+impl Procedure for PublicKeyTweakAdd {
+    type Output = Vec<u8>;
+
+    fn execute<R: Runner>(self, _runner: &R) -> Result<Self::Output, ProcedureError> {
+        use k256::elliptic_curve::{
+            group::Group,
+            sec1::{FromEncodedPoint, ToEncodedPoint},
+        };
+
+        let tweak_bytes: k256::FieldBytes =
+            k256::FieldBytes::from_exact_iter(self.tweak.iter().copied()).ok_or_else(|| {
+                FatalProcedureError::from(String::from("Es256k: Tweak must be exactly 32 bytes"))
+            })?;
+
+        let tweak = k256::Scalar::from_repr(tweak_bytes)
+            .into_option()
+            .ok_or_else(|| {
+                FatalProcedureError::from(String::from(
+                    "Es256k: Tweak is not less than the curve order",
+                ))
+            })?;
+
+        let parent_point = k256::EncodedPoint::from_bytes(&self.parent_public_key).map_err(|e| {
+            FatalProcedureError::from(String::from(format!(
+                "Es256k: Failed to parse parent public key {:?}",
+                e
+            )))
+        })?;
+
+        let parent_affine: k256::AffinePoint =
+            Option::from(k256::AffinePoint::from_encoded_point(&parent_point)).ok_or_else(
+                || {
+                    FatalProcedureError::from(String::from(
+                        "Es256k: Parent public key does not decode to a curve point",
+                    ))
+                },
+            )?;
+
+        let child_point =
+            k256::ProjectivePoint::from(parent_affine) + k256::ProjectivePoint::GENERATOR * tweak;
+
+        if bool::from(child_point.is_identity()) {
+            return Err(FatalProcedureError::from(String::from(
+                "Es256k: Tweaked public key is the point at infinity",
+            ))
+            .into());
+        }
+
+        Ok(child_point.to_affine().to_encoded_point(true).as_bytes().to_vec())
+    }
+}
+
 impl ProcedureExt for Es256kProcs {
     fn input(&self) -> Option<Location> {
         match self {
@@ -159,6 +531,10 @@ impl ProcedureExt for Es256kProcs {
             Es256kProcs::PublicKey(proc) => Some(proc.private_key.clone()),
             Es256kProcs::Sign(proc) => Some(proc.private_key.clone()),
             Es256kProcs::Verify(proc) => Some(proc.private_key.clone()),
+            Es256kProcs::Recover(_) => None,
+            Es256kProcs::DiffieHellman(proc) => Some(proc.private_key.clone()),
+            Es256kProcs::TweakAdd(proc) => Some(proc.parent_key.clone()),
+            Es256kProcs::PublicKeyTweakAdd(_) => None,
         }
     }
 
@@ -168,6 +544,10 @@ impl ProcedureExt for Es256kProcs {
             Es256kProcs::PublicKey(_) => None,
             Es256kProcs::Sign(_) => None,
             Es256kProcs::Verify(_) => None,
+            Es256kProcs::Recover(_) => None,
+            Es256kProcs::DiffieHellman(_) => None,
+            Es256kProcs::TweakAdd(proc) => Some(proc.output.clone()),
+            Es256kProcs::PublicKeyTweakAdd(_) => None,
         }
     }
 }
@@ -181,6 +561,10 @@ impl Procedure for Es256kProcs {
             Es256kProcs::PublicKey(proc) => proc.execute(runner).map(|o| o.into()),
             Es256kProcs::Sign(proc) => proc.execute(runner).map(|o| o.into()),
             Es256kProcs::Verify(proc) => proc.execute(runner).map(|o| o.into()),
+            Es256kProcs::Recover(proc) => proc.execute(runner).map(|o| o.into()),
+            Es256kProcs::DiffieHellman(proc) => proc.execute(runner).map(|o| o.into()),
+            Es256kProcs::TweakAdd(proc) => proc.execute(runner).map(|o| o.into()),
+            Es256kProcs::PublicKeyTweakAdd(proc) => proc.execute(runner).map(|o| o.into()),
         }
     }
 }
@@ -213,6 +597,8 @@ mod tests {
         let sign = Es256kProcs::Sign(Sign {
             msg: b"test".to_vec(),
             private_key: sk_loc.clone(),
+            recoverable: false,
+            prehashed: false,
         });
 
         // Chain together the public key and sign procedures.
@@ -233,6 +619,156 @@ mod tests {
             msg: b"test".to_vec(),
             signature: sig.into(),
             private_key: sk_loc.clone(),
+            prehashed: false,
+        });
+
+        let res: [u8; 1] = execute_procedure_ext(&client, verify)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(res[0], 1);
+    }
+
+    #[test]
+    fn test_es256k_recover() {
+        let stronghold = Stronghold::default();
+        let client = stronghold.create_client(b"test_es256k_recover").unwrap();
+
+        let sk_loc = Location::generic(b"secret_key".to_vec(), b"record".to_vec());
+
+        let gen_key = Es256kProcs::GenerateKey(GenerateKey {
+            output: sk_loc.clone(),
+        });
+
+        let _ = execute_procedure_ext(&client, gen_key).unwrap();
+
+        let pub_key = Es256kProcs::PublicKey(PublicKey {
+            private_key: sk_loc.clone(),
+        });
+
+        let sign = Es256kProcs::Sign(Sign {
+            msg: b"test".to_vec(),
+            private_key: sk_loc.clone(),
+            recoverable: true,
+            prehashed: false,
+        });
+
+        let res = execute_procedure_chained_ext(&client, vec![pub_key, sign]).unwrap();
+
+        let pk: Vec<u8> = res[0].clone().into();
+        let sig: Vec<u8> = res[1].clone().into();
+
+        // Recoverable signatures are a 65-byte [r || s || v] blob.
+        assert_eq!(sig.len(), 65);
+
+        let recover = Es256kProcs::Recover(Recover {
+            msg: b"test".to_vec(),
+            signature: sig,
+            prehashed: false,
+        });
+
+        let recovered_pk: Vec<u8> = execute_procedure_ext(&client, recover).unwrap().into();
+
+        assert_eq!(recovered_pk, pk);
+    }
+
+    #[test]
+    fn test_es256k_diffie_hellman() {
+        let stronghold = Stronghold::default();
+        let client = stronghold.create_client(b"test_es256k_diffie_hellman").unwrap();
+
+        let alice_loc = Location::generic(b"alice".to_vec(), b"record".to_vec());
+        let bob_loc = Location::generic(b"bob".to_vec(), b"record".to_vec());
+
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::GenerateKey(GenerateKey {
+                output: alice_loc.clone(),
+            }),
+        )
+        .unwrap();
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::GenerateKey(GenerateKey {
+                output: bob_loc.clone(),
+            }),
+        )
+        .unwrap();
+
+        let alice_pk: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::PublicKey(PublicKey {
+                private_key: alice_loc.clone(),
+            }),
+        )
+        .unwrap()
+        .into();
+        let bob_pk: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::PublicKey(PublicKey {
+                private_key: bob_loc.clone(),
+            }),
+        )
+        .unwrap()
+        .into();
+
+        let alice_secret: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::DiffieHellman(DiffieHellman {
+                private_key: alice_loc.clone(),
+                peer_public_key: bob_pk,
+                output: DiffieHellmanOutput::HashedX,
+            }),
+        )
+        .unwrap()
+        .into();
+
+        let bob_secret: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::DiffieHellman(DiffieHellman {
+                private_key: bob_loc,
+                peer_public_key: alice_pk,
+                output: DiffieHellmanOutput::HashedX,
+            }),
+        )
+        .unwrap()
+        .into();
+
+        assert_eq!(alice_secret.len(), 32);
+        assert_eq!(alice_secret, bob_secret);
+    }
+
+    #[test]
+    fn test_es256k_sign_prehashed() {
+        let stronghold = Stronghold::default();
+        let client = stronghold.create_client(b"test_es256k_sign_prehashed").unwrap();
+
+        let sk_loc = Location::generic(b"secret_key".to_vec(), b"record".to_vec());
+
+        let gen_key = Es256kProcs::GenerateKey(GenerateKey {
+            output: sk_loc.clone(),
+        });
+
+        let _ = execute_procedure_ext(&client, gen_key).unwrap();
+
+        // a 32-byte digest, as if it were keccak256(transaction).
+        let digest = vec![7u8; 32];
+
+        let sign = Es256kProcs::Sign(Sign {
+            msg: digest.clone(),
+            private_key: sk_loc.clone(),
+            recoverable: false,
+            prehashed: true,
+        });
+
+        let sig: Vec<u8> = execute_procedure_ext(&client, sign).unwrap().into();
+
+        let verify = Es256kProcs::Verify(Verify {
+            msg: digest.clone(),
+            signature: sig,
+            private_key: sk_loc.clone(),
+            prehashed: true,
         });
 
         let res: [u8; 1] = execute_procedure_ext(&client, verify)
@@ -241,5 +777,207 @@ mod tests {
             .unwrap();
 
         assert_eq!(res[0], 1);
+
+        // a message that isn't exactly 32 bytes must be rejected.
+        let sign = Es256kProcs::Sign(Sign {
+            msg: b"not 32 bytes".to_vec(),
+            private_key: sk_loc,
+            recoverable: false,
+            prehashed: true,
+        });
+
+        assert!(execute_procedure_ext(&client, sign).is_err());
+    }
+
+    #[test]
+    fn test_es256k_recover_prehashed() {
+        let stronghold = Stronghold::default();
+        let client = stronghold
+            .create_client(b"test_es256k_recover_prehashed")
+            .unwrap();
+
+        let sk_loc = Location::generic(b"secret_key".to_vec(), b"record".to_vec());
+
+        let gen_key = Es256kProcs::GenerateKey(GenerateKey {
+            output: sk_loc.clone(),
+        });
+
+        let _ = execute_procedure_ext(&client, gen_key).unwrap();
+
+        let pub_key = Es256kProcs::PublicKey(PublicKey {
+            private_key: sk_loc.clone(),
+        });
+
+        // a 32-byte digest, as if it were keccak256(transaction).
+        let digest = vec![7u8; 32];
+
+        let sign = Es256kProcs::Sign(Sign {
+            msg: digest.clone(),
+            private_key: sk_loc.clone(),
+            recoverable: true,
+            prehashed: true,
+        });
+
+        let res = execute_procedure_chained_ext(&client, vec![pub_key, sign]).unwrap();
+
+        let pk: Vec<u8> = res[0].clone().into();
+        let sig: Vec<u8> = res[1].clone().into();
+        assert_eq!(sig.len(), 65);
+
+        let recover = Es256kProcs::Recover(Recover {
+            msg: digest,
+            signature: sig,
+            prehashed: true,
+        });
+
+        let recovered_pk: Vec<u8> = execute_procedure_ext(&client, recover).unwrap().into();
+
+        assert_eq!(recovered_pk, pk);
+    }
+
+    #[test]
+    fn test_es256k_tweak_add() {
+        let stronghold = Stronghold::default();
+        let client = stronghold.create_client(b"test_es256k_tweak_add").unwrap();
+
+        let parent_loc = Location::generic(b"parent".to_vec(), b"record".to_vec());
+        let child_loc = Location::generic(b"child".to_vec(), b"record".to_vec());
+
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::GenerateKey(GenerateKey {
+                output: parent_loc.clone(),
+            }),
+        )
+        .unwrap();
+
+        let parent_pk: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::PublicKey(PublicKey {
+                private_key: parent_loc.clone(),
+            }),
+        )
+        .unwrap()
+        .into();
+
+        let tweak = vec![1u8; 32];
+
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::TweakAdd(TweakAdd {
+                parent_key: parent_loc,
+                tweak: tweak.clone(),
+                output: child_loc.clone(),
+            }),
+        )
+        .unwrap();
+
+        let child_pk: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::PublicKey(PublicKey {
+                private_key: child_loc,
+            }),
+        )
+        .unwrap()
+        .into();
+
+        let tweaked_parent_pk: Vec<u8> = execute_procedure_ext(
+            &client,
+            Es256kProcs::PublicKeyTweakAdd(PublicKeyTweakAdd {
+                parent_public_key: parent_pk,
+                tweak,
+            }),
+        )
+        .unwrap()
+        .into();
+
+        // Deriving the child key in the vault and tweaking the parent public
+        // key directly must land on the same child public key.
+        assert_eq!(child_pk, tweaked_parent_pk);
+    }
+
+    #[test]
+    fn test_es256k_tweak_add_rejects_wrong_length_tweak() {
+        let stronghold = Stronghold::default();
+        let client = stronghold
+            .create_client(b"test_es256k_tweak_add_rejects_wrong_length_tweak")
+            .unwrap();
+
+        let parent_loc = Location::generic(b"parent".to_vec(), b"record".to_vec());
+        let child_loc = Location::generic(b"child".to_vec(), b"record".to_vec());
+
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::GenerateKey(GenerateKey {
+                output: parent_loc.clone(),
+            }),
+        )
+        .unwrap();
+
+        let tweak_add = Es256kProcs::TweakAdd(TweakAdd {
+            parent_key: parent_loc,
+            tweak: vec![1u8; 31],
+            output: child_loc,
+        });
+
+        assert!(execute_procedure_ext(&client, tweak_add).is_err());
+    }
+
+    #[test]
+    fn test_es256k_tweak_add_rejects_tweak_not_less_than_curve_order() {
+        let stronghold = Stronghold::default();
+        let client = stronghold
+            .create_client(b"test_es256k_tweak_add_rejects_tweak_not_less_than_curve_order")
+            .unwrap();
+
+        let parent_loc = Location::generic(b"parent".to_vec(), b"record".to_vec());
+        let child_loc = Location::generic(b"child".to_vec(), b"record".to_vec());
+
+        let _ = execute_procedure_ext(
+            &client,
+            Es256kProcs::GenerateKey(GenerateKey {
+                output: parent_loc.clone(),
+            }),
+        )
+        .unwrap();
+
+        // 32 bytes of 0xff is far larger than the secp256k1 curve order.
+        let tweak_add = Es256kProcs::TweakAdd(TweakAdd {
+            parent_key: parent_loc,
+            tweak: vec![0xffu8; 32],
+            output: child_loc,
+        });
+
+        assert!(execute_procedure_ext(&client, tweak_add).is_err());
+    }
+
+    #[test]
+    fn test_public_key_tweak_add_rejects_identity_result() {
+        let stronghold = Stronghold::default();
+        let client = stronghold
+            .create_client(b"test_public_key_tweak_add_rejects_identity_result")
+            .unwrap();
+
+        // Pick a tweak and a "parent" public key that is its exact negation,
+        // so `parent + tweak*G` lands on the point at infinity. This is the
+        // public-key-side analogue of `TweakAdd`'s zero-child-key rejection,
+        // and unlike the vault-secret path it can be set up without knowing
+        // any private scalar.
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let tweak_scalar = k256::Scalar::from(42u64);
+        let parent_point = k256::ProjectivePoint::GENERATOR * (-tweak_scalar);
+        let parent_public_key = parent_point
+            .to_affine()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+
+        let tweak_add = Es256kProcs::PublicKeyTweakAdd(PublicKeyTweakAdd {
+            parent_public_key,
+            tweak: tweak_scalar.to_bytes().to_vec(),
+        });
+
+        assert!(execute_procedure_ext(&client, tweak_add).is_err());
     }
 }
@@ -0,0 +1,2 @@
+pub mod es256k;
+pub mod schnorr_secp256k1;
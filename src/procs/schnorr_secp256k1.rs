@@ -0,0 +1,245 @@
+use crate::{ext_procs, generic_procedures, ProcedureExt};
+
+use engine::runtime::memories::buffer::Buffer;
+use iota_stronghold::{
+    procedures::{FatalProcedureError, GenerateSecret, ProcedureOutput, Products, UseSecret},
+    Location,
+};
+
+use iota_stronghold::procedures::{Procedure, ProcedureError, Runner};
+use k256::schnorr::{
+    signature::{Signer, Verifier},
+    Signature, SigningKey,
+};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use stronghold_utils::GuardDebug;
+use zeroize::Zeroizing;
+
+/// BIP340 Schnorr procedures over secp256k1, mirroring [`crate::procs::es256k::Es256kProcs`]
+/// but producing Taproot-style keys and signatures: 32-byte x-only public keys and
+/// 64-byte `R.x || s` signatures.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub enum SchnorrSecp256k1Procs {
+    GenerateKey(GenerateKey),
+    PublicKey(PublicKey),
+    Sign(Sign),
+    Verify(Verify),
+}
+
+/// Returns the 32-byte x-only public key for an already existing private key in the vault.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct PublicKey {
+    pub private_key: Location,
+}
+
+/// Generates a random Schnorr private key and stores it in the vault at the supplied [`Location`].
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct GenerateKey {
+    pub output: Location,
+}
+
+/// Signs a message using the indicated private key from the vault, producing a
+/// BIP340 `R.x || s` signature (64 bytes) with an even-y nonce point.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct Sign {
+    pub msg: Vec<u8>,
+    pub private_key: Location,
+}
+
+/// Verifies that a message was signed by the indicated private key in the vault.
+/// Generates a new public key to preform this verification. The public key is discarded.
+/// Returns 1 if the signature is valid, 0 otherwise.
+#[derive(Clone, GuardDebug, Serialize, Deserialize)]
+pub struct Verify {
+    pub msg: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub private_key: Location,
+}
+
+generic_procedures!(SchnorrSecp256k1Procs, UseSecret<1> => {PublicKey, Sign, Verify});
+ext_procs!(SchnorrSecp256k1Procs, GenerateSecret => {GenerateKey});
+
+impl UseSecret<1> for PublicKey {
+    type Output = Vec<u8>;
+
+    fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        let sk = SigningKey::from_bytes(&guard[0].borrow()).map_err(|e| {
+            String::from(format!(
+                "SchnorrSecp256k1: Failed to get signing key from guard {:?}",
+                e
+            ))
+        })?;
+
+        Ok(sk.verifying_key().to_bytes().to_vec())
+    }
+
+    fn source(&self) -> [Location; 1] {
+        [self.private_key.clone()]
+    }
+}
+
+impl UseSecret<1> for Sign {
+    type Output = Vec<u8>;
+
+    fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        let sk = SigningKey::from_bytes(&guard[0].borrow()).map_err(|e| {
+            String::from(format!(
+                "SchnorrSecp256k1: Failed to get signing key from guard {:?}",
+                e
+            ))
+        })?;
+
+        let sig = sk
+            .try_sign(&self.msg)
+            .map_err(|e| String::from(format!("SchnorrSecp256k1: Failed to sign message {:?}", e)))?;
+
+        Ok(sig.to_bytes().to_vec())
+    }
+
+    fn source(&self) -> [Location; 1] {
+        [self.private_key.clone()]
+    }
+}
+
+impl UseSecret<1> for Verify {
+    type Output = Vec<u8>;
+
+    fn use_secret(self, guard: [Buffer<u8>; 1]) -> Result<Self::Output, FatalProcedureError> {
+        let sk = SigningKey::from_bytes(&guard[0].borrow()).map_err(|e| {
+            String::from(format!(
+                "SchnorrSecp256k1: Failed to get signing key from guard {:?}",
+                e
+            ))
+        })?;
+
+        let sig = Signature::try_from(self.signature.as_slice()).map_err(|e| {
+            String::from(format!(
+                "SchnorrSecp256k1: Failed to get signature from vector {:?}",
+                e
+            ))
+        })?;
+
+        let vk = sk.verifying_key();
+        let res = vk.verify(&self.msg, &sig).is_ok();
+
+        if res {
+            Ok(u8::to_be_bytes(1).to_vec())
+        } else {
+            Ok(u8::to_be_bytes(0).to_vec())
+        }
+    }
+
+    fn source(&self) -> [Location; 1] {
+        [self.private_key.clone()]
+    }
+}
+
+impl GenerateSecret for GenerateKey {
+    type Output = ();
+
+    fn generate(self) -> Result<Products<Self::Output>, FatalProcedureError> {
+        let sk = SigningKey::random(&mut OsRng);
+        let sk = sk.to_bytes().to_vec();
+
+        Ok(Products {
+            secret: Zeroizing::new(sk),
+            output: (),
+        })
+    }
+
+    fn target(&self) -> &Location {
+        &self.output
+    }
+}
+
+impl ProcedureExt for SchnorrSecp256k1Procs {
+    fn input(&self) -> Option<Location> {
+        match self {
+            SchnorrSecp256k1Procs::GenerateKey(_) => None,
+            SchnorrSecp256k1Procs::PublicKey(proc) => Some(proc.private_key.clone()),
+            SchnorrSecp256k1Procs::Sign(proc) => Some(proc.private_key.clone()),
+            SchnorrSecp256k1Procs::Verify(proc) => Some(proc.private_key.clone()),
+        }
+    }
+
+    fn output(&self) -> Option<Location> {
+        match self {
+            SchnorrSecp256k1Procs::GenerateKey(proc) => Some(proc.output.clone()),
+            SchnorrSecp256k1Procs::PublicKey(_) => None,
+            SchnorrSecp256k1Procs::Sign(_) => None,
+            SchnorrSecp256k1Procs::Verify(_) => None,
+        }
+    }
+}
+
+impl Procedure for SchnorrSecp256k1Procs {
+    type Output = ProcedureOutput;
+
+    fn execute<R: Runner>(self, runner: &R) -> Result<Self::Output, ProcedureError> {
+        match self {
+            SchnorrSecp256k1Procs::GenerateKey(proc) => proc.execute(runner).map(|o| o.into()),
+            SchnorrSecp256k1Procs::PublicKey(proc) => proc.execute(runner).map(|o| o.into()),
+            SchnorrSecp256k1Procs::Sign(proc) => proc.execute(runner).map(|o| o.into()),
+            SchnorrSecp256k1Procs::Verify(proc) => proc.execute(runner).map(|o| o.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iota_stronghold::Stronghold;
+
+    use crate::{execute_procedure_chained_ext, execute_procedure_ext};
+
+    #[test]
+    fn test_schnorr_secp256k1_procs() {
+        let stronghold = Stronghold::default();
+        let client = stronghold
+            .create_client(b"test_schnorr_secp256k1_procs")
+            .unwrap();
+
+        let sk_loc = Location::generic(b"secret_key".to_vec(), b"record".to_vec());
+
+        let gen_key = SchnorrSecp256k1Procs::GenerateKey(GenerateKey {
+            output: sk_loc.clone(),
+        });
+
+        // create a schnorr secret key and put it into the stronghold vault.
+        let _ = execute_procedure_ext(&client, gen_key).unwrap();
+
+        let pub_key = SchnorrSecp256k1Procs::PublicKey(PublicKey {
+            private_key: sk_loc.clone(),
+        });
+
+        let sign = SchnorrSecp256k1Procs::Sign(Sign {
+            msg: b"test".to_vec(),
+            private_key: sk_loc.clone(),
+        });
+
+        // Chain together the public key and sign procedures.
+        let res = execute_procedure_chained_ext(&client, vec![pub_key, sign]).unwrap();
+
+        let pk: Vec<u8> = res[0].clone().into();
+        // x-only public keys are 32 bytes.
+        assert_eq!(pk.len(), 32);
+
+        let sig: Vec<u8> = res[1].clone().into();
+        // BIP340 signatures are 64 bytes: R.x || s.
+        assert_eq!(sig.len(), 64);
+
+        let verify = SchnorrSecp256k1Procs::Verify(Verify {
+            msg: b"test".to_vec(),
+            signature: sig,
+            private_key: sk_loc.clone(),
+        });
+
+        let res: [u8; 1] = execute_procedure_ext(&client, verify)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(res[0], 1);
+    }
+}